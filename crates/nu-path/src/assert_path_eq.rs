@@ -0,0 +1,10 @@
+/// Assert two paths are equal, normalizing through `PathBuf::from` first so
+/// tests can compare a `&str` and a `PathBuf` without extra conversions.
+#[macro_export]
+macro_rules! assert_path_eq {
+    ($left:expr, $right:expr $(,)?) => {{
+        let left = std::path::PathBuf::from($left);
+        let right = std::path::PathBuf::from($right);
+        assert_eq!(left, right);
+    }};
+}
@@ -0,0 +1,118 @@
+use crate::form::virtual_prefix;
+use std::path::Path;
+
+/// Split `path` into its individual components as strings, treating both `/`
+/// and `\` as separators regardless of the host platform. Repeated
+/// separators produce no empty segments.
+///
+/// If `path` starts with a recognized virtual-root prefix (see
+/// [`crate::form`]), only the portion after that prefix is split; the
+/// prefix itself isn't a local path component.
+///
+/// On Windows, a leading UNC server/share pair (`\\server\share\...`) is
+/// kept together as a single component, the same way a drive letter (`C:`)
+/// is: splitting `server` and `share` into separate components would make a
+/// UNC root indistinguishable from an ordinary two-segment relative path.
+/// This doesn't apply off Windows: a leading `//` there is just an ordinary
+/// (if unusual) absolute path, e.g. an NFS mount like `//mnt/share/file`,
+/// and merging its first two segments would corrupt it.
+pub fn components(path: impl AsRef<Path>) -> Vec<String> {
+    let path_str = match virtual_prefix(path.as_ref()) {
+        Some((_, rest)) => rest,
+        None => path.as_ref().to_string_lossy().to_string(),
+    };
+
+    if let Some(unc_root) = unc_root(&path_str) {
+        let rest = &path_str[unc_root.len()..];
+        let mut components = vec![unc_root];
+        components.extend(
+            rest.split(['/', '\\'])
+                .filter(|segment| !segment.is_empty())
+                .map(str::to_string),
+        );
+        return components;
+    }
+
+    path_str
+        .split(['/', '\\'])
+        .filter(|segment| !segment.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// If `path_str` starts with a UNC server/share prefix (`\\server\share` or
+/// `//server/share`), return that prefix, separators normalized to `\`.
+/// Windows-only: see [`components`]'s doc comment for why this isn't
+/// meaningful on other platforms.
+#[cfg(windows)]
+fn unc_root(path_str: &str) -> Option<String> {
+    let rest = path_str.strip_prefix(['/', '\\'])?;
+    let rest = rest.strip_prefix(['/', '\\'])?;
+    let mut segments = rest.splitn(3, ['/', '\\']);
+    let server = segments.next().filter(|s| !s.is_empty())?;
+    let share = segments.next().filter(|s| !s.is_empty())?;
+    Some(format!(r"\\{server}\{share}"))
+}
+
+#[cfg(not(windows))]
+fn unc_root(_path_str: &str) -> Option<String> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_unix_style_path() {
+        assert_eq!(components("/foo/bar/baz"), vec!["foo", "bar", "baz"]);
+    }
+
+    #[test]
+    fn splits_windows_style_path() {
+        assert_eq!(components(r"C:\foo\bar"), vec!["C:", "foo", "bar"]);
+    }
+
+    #[test]
+    fn skips_repeated_separators() {
+        assert_eq!(components("foo//bar"), vec!["foo", "bar"]);
+    }
+
+    #[test]
+    fn skips_virtual_prefix_before_splitting() {
+        assert_eq!(components("remote://host/a/b"), vec!["host", "a", "b"]);
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn keeps_unc_server_share_as_one_component() {
+        assert_eq!(
+            components(r"\\server\share\dir\file.txt"),
+            vec![r"\\server\share", "dir", "file.txt"]
+        );
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn keeps_forward_slash_unc_server_share_as_one_component() {
+        assert_eq!(
+            components("//server/share/dir"),
+            vec![r"\\server\share", "dir"]
+        );
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn unc_root_alone_is_a_single_component() {
+        assert_eq!(components(r"\\server\share"), vec![r"\\server\share"]);
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn leaves_double_slash_nfs_style_path_unmerged() {
+        assert_eq!(
+            components("//mnt/share/file"),
+            vec!["mnt", "share", "file"]
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use std::path::{Component, Path, PathBuf};
+
+/// Lexically resolve `.` and `..` components in `path`, without touching the
+/// filesystem and without requiring the path to exist. A leading `..` that
+/// would escape the start of the path is kept as-is, since there's no root to
+/// pop past.
+pub fn expand_dots(path: impl AsRef<Path>) -> PathBuf {
+    let mut result = PathBuf::new();
+    for component in path.as_ref().components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match result.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    result.pop();
+                }
+                _ => result.push(".."),
+            },
+            other => result.push(other.as_os_str()),
+        }
+    }
+    if result.as_os_str().is_empty() {
+        PathBuf::from(".")
+    } else {
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collapses_current_dir_segments() {
+        assert_eq!(
+            expand_dots(Path::new("foo/./bar")),
+            PathBuf::from("foo/bar")
+        );
+    }
+
+    #[test]
+    fn collapses_parent_dir_segments() {
+        assert_eq!(
+            expand_dots(Path::new("foo/bar/../baz")),
+            PathBuf::from("foo/baz")
+        );
+    }
+
+    #[test]
+    fn keeps_leading_parent_dir_segments() {
+        assert_eq!(expand_dots(Path::new("../../foo")), PathBuf::from("../../foo"));
+    }
+
+    #[test]
+    fn empty_result_becomes_current_dir() {
+        assert_eq!(expand_dots(Path::new("foo/..")), PathBuf::from("."));
+    }
+}
@@ -0,0 +1,275 @@
+use crate::{
+    dots::expand_dots,
+    form::{self, virtual_prefix, Form},
+    path::absolutize,
+    tilde::expand_tilde_with_home,
+};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Expand `~` and `.`/`..` segments in `path`, relative to `cwd`, without
+/// touching the filesystem. `path` is made absolute against `cwd` first if
+/// it isn't already.
+///
+/// If `path` starts with a recognized virtual-root prefix (see
+/// [`crate::form`]), it's returned unchanged: a non-local root has no
+/// meaningful `cwd` to resolve against, and naively joining `cwd` onto it
+/// would silently corrupt the scheme/mount path.
+pub fn expand_path_with(
+    path: impl AsRef<Path>,
+    cwd: impl AsRef<Path>,
+    expand_tilde: bool,
+) -> PathBuf {
+    let path = path.as_ref();
+    if virtual_prefix(path).is_some() {
+        return path.to_owned();
+    }
+    let path = if expand_tilde {
+        expand_tilde_with_home(path, crate::home_dir())
+    } else {
+        path.to_owned()
+    };
+    expand_dots(absolutize(cwd, path))
+}
+
+/// Resolve `path` to the real path on disk, following symlinks. Falls back to
+/// [`expand_path_with`] (relative to the current directory) if the path
+/// doesn't exist or can't be canonicalized.
+pub fn expand_to_real_path(path: impl AsRef<Path>) -> PathBuf {
+    let path = path.as_ref();
+    std::fs::canonicalize(path).unwrap_or_else(|_| expand_path_with(path, ".", true))
+}
+
+/// Like [`std::fs::canonicalize`], but resolves `path` relative to `cwd`
+/// rather than the process's current directory, and expands a leading `~`
+/// first.
+///
+/// A virtual-root path (see [`crate::form`]) is returned unchanged rather
+/// than handed to `std::fs::canonicalize`: there's no local filesystem entry
+/// for it to resolve against, so canonicalizing would just fail (or, worse,
+/// resolve against the real cwd if a future refactor loosens this check).
+pub fn canonicalize_with(path: impl AsRef<Path>, cwd: impl AsRef<Path>) -> io::Result<PathBuf> {
+    let path = path.as_ref();
+    if virtual_prefix(path).is_some() {
+        return Ok(path.to_owned());
+    }
+    let canonical = std::fs::canonicalize(expand_path_with(path, cwd, true))?;
+    // `form::form` classifies purely from the string, so it can't tell a
+    // canonicalized path from any other absolute one (that's why it never
+    // returns `Form::Canonical` — see its doc comment). This is the call
+    // site that actually produces paths meeting that stronger guarantee;
+    // the assertion documents the relationship in code rather than leaving
+    // `Form::Canonical` as a variant nothing ever produces or checks for.
+    debug_assert_eq!(form::form(&canonical), Form::Absolute);
+    Ok(canonical)
+}
+
+/// Search `dirs`, in order, for a file or directory named `name`, returning
+/// the first path (relative to `cwd`) that exists.
+pub fn locate_in_dirs<I>(
+    name: impl AsRef<Path>,
+    cwd: impl AsRef<Path>,
+    dirs: impl FnOnce() -> I,
+) -> Option<PathBuf>
+where
+    I: Iterator<Item = PathBuf>,
+{
+    let name = name.as_ref();
+    for dir in dirs() {
+        let candidate = expand_path_with(dir.join(name), &cwd, true);
+        if candidate.exists() {
+            return Some(candidate);
+        }
+    }
+    None
+}
+
+/// Expand `%VAR%` (Windows-style), `$VAR`, `${VAR}`, and `$env.VAR` references
+/// in `path` using `lookup`, then run the usual `~` and `.`/`..` expansion. A
+/// token `lookup` doesn't resolve is left untouched rather than causing an
+/// error, so config code can store portable path templates that resolve
+/// partially depending on what's set in a given environment.
+pub fn expand_path_with_vars(
+    path: impl AsRef<Path>,
+    cwd: impl AsRef<Path>,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> PathBuf {
+    let substituted = substitute_env_vars(&path.as_ref().to_string_lossy(), &lookup);
+    expand_path_with(substituted, cwd, true)
+}
+
+/// Scan `input` for `%VAR%`, `$VAR`, `${VAR}`, and `$env.VAR` tokens and
+/// replace each with `lookup`'s result. Tokens `lookup` returns `None` for
+/// are copied to the output unchanged.
+fn substitute_env_vars(input: &str, lookup: &impl Fn(&str) -> Option<String>) -> String {
+    let chars: Vec<char> = input.chars().collect();
+    let mut result = String::with_capacity(input.len());
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '%' => {
+                if let Some(len) = chars[i + 1..].iter().position(|&c| c == '%') {
+                    let name: String = chars[i + 1..i + 1 + len].iter().collect();
+                    if !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                        match lookup(&name) {
+                            Some(value) => result.push_str(&value),
+                            None => result.push_str(&format!("%{name}%")),
+                        }
+                        i += len + 2;
+                        continue;
+                    }
+                }
+                result.push('%');
+                i += 1;
+            }
+            '$' if chars.get(i + 1) == Some(&'{') => {
+                if let Some(len) = chars[i + 2..].iter().position(|&c| c == '}') {
+                    let name: String = chars[i + 2..i + 2 + len].iter().collect();
+                    match lookup(&name) {
+                        Some(value) => result.push_str(&value),
+                        None => result.push_str(&format!("${{{name}}}")),
+                    }
+                    i += len + 3;
+                    continue;
+                }
+                result.push('$');
+                i += 1;
+            }
+            '$' => {
+                let start = i + 1;
+                let mut end = start;
+                while end < chars.len() && (chars[end].is_alphanumeric() || chars[end] == '_') {
+                    end += 1;
+                }
+                if end > start {
+                    let name: String = chars[start..end].iter().collect();
+                    // `$env.NAME` is the same env-var reference as `$NAME`, just
+                    // spelled the way Nushell scripts spell it; accept it so
+                    // paths copied out of config code don't need rewriting.
+                    if name == "env" && chars.get(end) == Some(&'.') {
+                        let dotted_start = end + 1;
+                        let mut dotted_end = dotted_start;
+                        while dotted_end < chars.len()
+                            && (chars[dotted_end].is_alphanumeric() || chars[dotted_end] == '_')
+                        {
+                            dotted_end += 1;
+                        }
+                        if dotted_end > dotted_start {
+                            let dotted_name: String =
+                                chars[dotted_start..dotted_end].iter().collect();
+                            match lookup(&dotted_name) {
+                                Some(value) => result.push_str(&value),
+                                None => result.push_str(&format!("$env.{dotted_name}")),
+                            }
+                            i = dotted_end;
+                            continue;
+                        }
+                    }
+                    match lookup(&name) {
+                        Some(value) => {
+                            result.push_str(&value);
+                            i = end;
+                            continue;
+                        }
+                        None => {
+                            result.push_str(&format!("${name}"));
+                            i = end;
+                            continue;
+                        }
+                    }
+                }
+                result.push('$');
+                i += 1;
+            }
+            c => {
+                result.push(c);
+                i += 1;
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn lookup(vars: &HashMap<&str, &str>) -> impl Fn(&str) -> Option<String> + '_ {
+        move |name| vars.get(name).map(|v| v.to_string())
+    }
+
+    #[test]
+    fn expands_dollar_style_var() {
+        let mut vars = HashMap::new();
+        vars.insert("FOO", "/data");
+        assert_eq!(
+            substitute_env_vars("$FOO/bar", &lookup(&vars)),
+            "/data/bar"
+        );
+    }
+
+    #[test]
+    fn expands_braced_dollar_style_var() {
+        let mut vars = HashMap::new();
+        vars.insert("XDG_CACHE_HOME", "/home/nu/.cache");
+        assert_eq!(
+            substitute_env_vars("${XDG_CACHE_HOME}/nu", &lookup(&vars)),
+            "/home/nu/.cache/nu"
+        );
+    }
+
+    #[test]
+    fn expands_percent_style_var() {
+        let mut vars = HashMap::new();
+        vars.insert("USERPROFILE", r"C:\Users\nu");
+        assert_eq!(
+            substitute_env_vars(r"%USERPROFILE%\bin", &lookup(&vars)),
+            r"C:\Users\nu\bin"
+        );
+    }
+
+    #[test]
+    fn expands_dollar_env_dot_style_var() {
+        let mut vars = HashMap::new();
+        vars.insert("FOO", "/data");
+        assert_eq!(
+            substitute_env_vars("$env.FOO/bar", &lookup(&vars)),
+            "/data/bar"
+        );
+    }
+
+    #[test]
+    fn leaves_unresolved_tokens_untouched() {
+        let vars = HashMap::new();
+        assert_eq!(substitute_env_vars("$NOT_SET/bar", &lookup(&vars)), "$NOT_SET/bar");
+        assert_eq!(
+            substitute_env_vars("%NOT_SET%/bar", &lookup(&vars)),
+            "%NOT_SET%/bar"
+        );
+    }
+
+    #[test]
+    fn expand_path_with_vars_runs_dots_and_tilde_after_substitution() {
+        let mut vars = HashMap::new();
+        vars.insert("BASE", "/home/nu/work");
+        let expanded = expand_path_with_vars("$BASE/../other", "/", lookup(&vars));
+        assert_eq!(expanded, PathBuf::from("/home/nu/other"));
+    }
+
+    #[test]
+    fn expand_path_with_leaves_virtual_paths_unchanged() {
+        assert_eq!(
+            expand_path_with("remote://host/../a", "/cwd", true),
+            PathBuf::from("remote://host/../a")
+        );
+    }
+
+    #[test]
+    fn canonicalize_with_leaves_virtual_paths_unchanged() {
+        assert_eq!(
+            canonicalize_with("remote://host/../a", "/cwd").unwrap(),
+            PathBuf::from("remote://host/../a")
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use std::path::Path;
+
+/// Which "form" a path is in: whether it's relative to some base, already
+/// absolute, fully canonical (absolute, symlinks resolved, no `.`/`..`
+/// segments), or rooted in a non-local scheme/virtual mount rather than the
+/// local filesystem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Form {
+    Relative,
+    Absolute,
+    Canonical,
+    Virtual,
+}
+
+/// Recognized-prefix registry for non-local roots: any `scheme://` prefix
+/// (detected generically), plus a short list of bare virtual-mount prefixes
+/// that don't use the `://` shape. This is the groundwork a remote/overlay
+/// filesystem integration can register into, rather than nu-path trying to
+/// special-case every scheme itself.
+const VIRTUAL_MOUNT_PREFIXES: &[&str] = &["vfs:"];
+
+/// If `path` starts with a recognized virtual-root prefix, return that
+/// prefix and the remaining path portion after it. Otherwise, `None`.
+///
+/// ```
+/// use nu_path::form::virtual_prefix;
+///
+/// assert_eq!(
+///     virtual_prefix("remote://host/a/b"),
+///     Some(("remote://".to_string(), "host/a/b".to_string()))
+/// );
+/// assert_eq!(virtual_prefix("/local/path"), None);
+/// ```
+pub fn virtual_prefix(path: impl AsRef<Path>) -> Option<(String, String)> {
+    let path_str = path.as_ref().to_string_lossy();
+
+    if let Some(scheme_end) = path_str.find("://") {
+        let scheme = &path_str[..scheme_end];
+        // A bare "://" with no scheme name, or one containing a path
+        // separator, isn't a scheme prefix.
+        if !scheme.is_empty() && !scheme.contains(['/', '\\']) {
+            let prefix_len = scheme_end + "://".len();
+            return Some((path_str[..prefix_len].to_string(), path_str[prefix_len..].to_string()));
+        }
+    }
+
+    for prefix in VIRTUAL_MOUNT_PREFIXES {
+        if let Some(rest) = path_str.strip_prefix(prefix) {
+            return Some((prefix.to_string(), rest.to_string()));
+        }
+    }
+
+    None
+}
+
+/// Determine the [`Form`] of `path`: [`Form::Virtual`] if it matches a
+/// recognized virtual-root prefix, [`Form::Absolute`] if it's an absolute
+/// local path, [`Form::Relative`] otherwise. [`Form::Canonical`] is never
+/// produced here — it only describes paths actually produced by
+/// canonicalizing (see [`crate::canonicalize_with`]).
+pub fn form(path: impl AsRef<Path>) -> Form {
+    let path = path.as_ref();
+    if virtual_prefix(path).is_some() {
+        Form::Virtual
+    } else if path.is_absolute() {
+        Form::Absolute
+    } else {
+        Form::Relative
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_scheme_prefix() {
+        assert_eq!(
+            virtual_prefix("remote://host/a/b"),
+            Some(("remote://".to_string(), "host/a/b".to_string()))
+        );
+    }
+
+    #[test]
+    fn detects_registered_mount_prefix() {
+        assert_eq!(
+            virtual_prefix("vfs:/mnt/data"),
+            Some(("vfs:".to_string(), "/mnt/data".to_string()))
+        );
+    }
+
+    #[test]
+    fn local_paths_have_no_virtual_prefix() {
+        assert_eq!(virtual_prefix("/local/path"), None);
+        assert_eq!(virtual_prefix(r"C:\local\path"), None);
+        assert_eq!(virtual_prefix("relative/path"), None);
+    }
+
+    #[test]
+    fn form_classifies_local_and_virtual_paths() {
+        assert_eq!(form("relative/path"), Form::Relative);
+        assert_eq!(form("/abs/path"), Form::Absolute);
+        assert_eq!(form("remote://host/path"), Form::Virtual);
+    }
+}
@@ -0,0 +1,73 @@
+use std::path::PathBuf;
+
+/// Get the current user's home directory, if it can be determined.
+pub fn home_dir() -> Option<PathBuf> {
+    dirs::home_dir()
+}
+
+/// Get Nushell's config directory, e.g. `~/.config/nushell` on Linux.
+pub fn nu_config_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|mut path| {
+        path.push("nushell");
+        path
+    })
+}
+
+/// Get Nushell's data directory, e.g. `~/.local/share/nushell` on Linux.
+pub fn data_dir() -> Option<PathBuf> {
+    dirs::data_dir().map(|mut path| {
+        path.push("nushell");
+        path
+    })
+}
+
+/// Get Nushell's cache directory, e.g. `~/.cache/nushell` on Linux.
+pub fn cache_dir() -> Option<PathBuf> {
+    dirs::cache_dir().map(|mut path| {
+        path.push("nushell");
+        path
+    })
+}
+
+/// Ordered list of directories Nushell may load config from, honoring the
+/// XDG Base Directory layering on Linux/macOS: [`nu_config_dir`] (which
+/// already resolves `$XDG_CONFIG_HOME`, or the platform default) first, then
+/// each colon-separated entry of `$XDG_CONFIG_DIRS`, so a user config can
+/// override system-wide defaults like `/etc/xdg/nushell/config.nu`. Falls
+/// back to just `[nu_config_dir()]` when `$XDG_CONFIG_DIRS` is unset, which
+/// matches the prior single-directory behavior.
+pub fn nu_config_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+
+    if let Some(dir) = nu_config_dir() {
+        dirs.push(dir);
+    }
+
+    if let Ok(xdg_config_dirs) = std::env::var("XDG_CONFIG_DIRS") {
+        for dir in std::env::split_paths(&xdg_config_dirs) {
+            dirs.push(dir.join("nushell"));
+        }
+    }
+
+    dirs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nu_config_dirs_falls_back_to_single_dir_without_xdg_config_dirs() {
+        std::env::remove_var("XDG_CONFIG_DIRS");
+        assert_eq!(nu_config_dirs(), nu_config_dir().into_iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn nu_config_dirs_appends_each_xdg_config_dirs_entry() {
+        std::env::set_var("XDG_CONFIG_DIRS", "/etc/xdg:/usr/local/etc/xdg");
+        let dirs = nu_config_dirs();
+        assert!(dirs.contains(&PathBuf::from("/etc/xdg/nushell")));
+        assert!(dirs.contains(&PathBuf::from("/usr/local/etc/xdg/nushell")));
+        std::env::remove_var("XDG_CONFIG_DIRS");
+    }
+}
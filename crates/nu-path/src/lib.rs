@@ -12,10 +12,15 @@ mod tilde;
 mod trailing_slash;
 
 pub use components::components;
-pub use expansions::{canonicalize_with, expand_path_with, expand_to_real_path, locate_in_dirs};
-pub use helpers::{cache_dir, data_dir, home_dir, nu_config_dir};
+pub use expansions::{
+    canonicalize_with, expand_path_with, expand_path_with_vars, expand_to_real_path,
+    locate_in_dirs,
+};
+pub use helpers::{cache_dir, data_dir, home_dir, nu_config_dir, nu_config_dirs};
 pub use path::*;
 #[cfg(windows)]
-pub use pwd_per_drive::_impl::singleton::{expand_pwd, set_pwd};
+pub use pwd_per_drive::shared_map::{expand_pwd, persist_to_disk, set_pwd};
+#[cfg(windows)]
+pub use pwd_per_drive::DriveToPwdMap;
 pub use tilde::expand_tilde;
 pub use trailing_slash::{has_trailing_slash, strip_trailing_slash};
@@ -0,0 +1,30 @@
+use std::path::{Path, PathBuf};
+
+/// Join `path` onto `base` if `path` is relative; otherwise return `path`
+/// unchanged.
+pub fn absolutize(base: impl AsRef<Path>, path: impl AsRef<Path>) -> PathBuf {
+    let path = path.as_ref();
+    if path.is_absolute() {
+        path.to_owned()
+    } else {
+        base.as_ref().join(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn joins_relative_path_onto_base() {
+        assert_eq!(
+            absolutize("/home/nu", "projects"),
+            PathBuf::from("/home/nu/projects")
+        );
+    }
+
+    #[test]
+    fn leaves_absolute_path_untouched() {
+        assert_eq!(absolutize("/home/nu", "/etc/nu"), PathBuf::from("/etc/nu"));
+    }
+}
@@ -84,6 +84,16 @@ pub mod shared_map {
             pwd_per_drive.get_env_vars(env);
         }
     }
+
+    /// Write the current PWD-per-drive map to [`DriveToPwdMap::persist_file_path`] so
+    /// the next session can rehydrate it. Meant to be called once, on clean exit.
+    pub fn persist_to_disk() -> Result<(), PathError> {
+        if let Ok(pwd_per_drive) = get_shared_drive_pwd_map().lock() {
+            pwd_per_drive.save_to_disk()
+        } else {
+            Err(CantLockSharedMap)
+        }
+    }
 }
 
 /// Helper to check if input path is relative path
@@ -98,16 +108,94 @@ fn need_expand(path: &Path) -> bool {
     false
 }
 
-struct DriveToPwdMap {
+/// Name of the file, relative to [`nu_path::cache_dir`](crate::cache_dir), that the
+/// last-known PWD for each drive is persisted to between sessions.
+const PERSIST_FILE_NAME: &str = "pwd_per_drive.nuon";
+
+/// Wrap `s` in double quotes, escaping `\` and `"` so it round-trips through
+/// [`unquote`] even if it contains a comma, colon, or quote.
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Reverse of [`quote`]: strip surrounding quotes (if present) and unescape
+/// `\\` and `\"`.
+fn unquote(s: &str) -> String {
+    let Some(inner) = s.strip_prefix('"').and_then(|s| s.strip_suffix('"')) else {
+        return s.to_string();
+    };
+    let mut result = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('"') => result.push('"'),
+                Some('\\') => result.push('\\'),
+                Some(other) => {
+                    result.push('\\');
+                    result.push(other);
+                }
+                None => result.push('\\'),
+            }
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// Split `s` on `delim`, but only outside double-quoted substrings, so a
+/// quoted field may itself contain `delim` (e.g. a Windows path with a
+/// comma). Each returned piece is trimmed of surrounding whitespace.
+fn split_top_level(s: &str, delim: char) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\\' && in_quotes {
+            current.push(c);
+            if let Some(next) = chars.next() {
+                current.push(next);
+            }
+        } else if c == '"' {
+            in_quotes = !in_quotes;
+            current.push(c);
+        } else if c == delim && !in_quotes {
+            let trimmed = current.trim();
+            if !trimmed.is_empty() {
+                parts.push(trimmed.to_string());
+            }
+            current = String::new();
+        } else {
+            current.push(c);
+        }
+    }
+    let trimmed = current.trim();
+    if !trimmed.is_empty() {
+        parts.push(trimmed.to_string());
+    }
+    parts
+}
+
+pub struct DriveToPwdMap {
     map: [Option<String>; 26], // Fixed-size array for A-Z
 }
 
 impl DriveToPwdMap {
     pub fn new() -> Self {
-        // Initialize by current PWD-per-drive
+        // Initialize by current PWD-per-drive, preferring (in order): the env vars
+        // the parent process exported, the map persisted by a previous session, and
+        // finally whatever the OS reports as the drive's current directory. This is
+        // the integration point `init_pwd_per_drive` (in the `nu` binary's run.rs)
+        // relies on: by the time `Stack::new()` constructs `stack.pwd_per_drive`
+        // (which goes through here), the persisted-file merge has already happened,
+        // so `init_pwd_per_drive` only needs to layer the *current* env vars and
+        // `engine_state.cwd()` on top of what's already in the map.
         let mut map: [Option<String>; 26] = Default::default();
+        let persisted = Self::load_persisted();
         for (drive_index, drive_letter) in ('A'..='Z').enumerate() {
-            let env_var = format!("={}:", drive_letter);
+            let env_var = Self::env_var_for_drive(drive_letter);
             if let Ok(env_pwd) = std::env::var(&env_var) {
                 if env_pwd.len() > 3 {
                     map[drive_index] = Some(env_pwd);
@@ -115,13 +203,108 @@ impl DriveToPwdMap {
                     continue;
                 }
             }
-            if let Some(pwd) = get_full_path_name_w(&format!("{}:", drive_letter)) {
-                if pwd.len() > 3 {
-                    map[drive_index] = Some(pwd);
+            map[drive_index] = Self::resolve_from_persisted_or_os(drive_letter, &persisted);
+        }
+        Self { map }
+    }
+
+    /// Resolve the PWD to use for `drive_letter` when no env var overrides it:
+    /// prefer the persisted value if its directory still exists, otherwise ask
+    /// the OS, as `new()` would have before persistence existed.
+    fn resolve_from_persisted_or_os(
+        drive_letter: char,
+        persisted: &HashMap<char, String>,
+    ) -> Option<String> {
+        if let Some(pwd) = persisted.get(&drive_letter.to_ascii_uppercase()) {
+            if Path::new(pwd).exists() {
+                return Some(pwd.clone());
+            }
+        }
+        get_full_path_name_w(&format!("{}:", drive_letter)).filter(|pwd| pwd.len() > 3)
+    }
+
+    /// Build the env var name a parent shell would use to export the PWD for
+    /// `drive_letter` (e.g. `'c'` -> `"=C:"`).
+    pub fn env_var_for_drive(drive_letter: char) -> String {
+        format!("={}:", drive_letter.to_ascii_uppercase())
+    }
+
+    /// Full path to the file the drive-to-PWD map is persisted to, if
+    /// [`nu_path::cache_dir`](crate::cache_dir) can be determined.
+    fn persist_file_path() -> Option<PathBuf> {
+        crate::cache_dir().map(|mut dir| {
+            dir.push(PERSIST_FILE_NAME);
+            dir
+        })
+    }
+
+    /// Load the map persisted at [`Self::persist_file_path`], if any.
+    fn load_persisted() -> HashMap<char, String> {
+        match Self::persist_file_path() {
+            Some(path) => Self::load_persisted_from(&path),
+            None => HashMap::new(),
+        }
+    }
+
+    /// Parse the NUON-ish `{DRIVE: "path", ...}` record previously written by
+    /// [`Self::save_to_disk_at`]. Unparseable content (or a missing file)
+    /// yields an empty map rather than an error, since a corrupt cache file
+    /// shouldn't block startup. Unlike a naive `split(',')`, this respects
+    /// quoted strings, so a persisted path containing a comma (legal on
+    /// Windows, e.g. `C:\Users\Smith, Jr\Desktop`) round-trips correctly.
+    fn load_persisted_from(path: &Path) -> HashMap<char, String> {
+        let mut result = HashMap::new();
+        let Ok(contents) = std::fs::read_to_string(path) else {
+            return result;
+        };
+        let trimmed = contents.trim().trim_start_matches('{').trim_end_matches('}');
+        for entry in split_top_level(trimmed, ',') {
+            let Some((drive, pwd)) = entry.split_once(':') else {
+                continue;
+            };
+            let drive = drive.trim();
+            let pwd = unquote(pwd.trim());
+            if let Some(drive_letter) = drive.chars().next() {
+                if drive_letter.is_ascii_alphabetic() && !pwd.is_empty() {
+                    result.insert(drive_letter.to_ascii_uppercase(), pwd);
                 }
             }
         }
-        Self { map }
+        result
+    }
+
+    /// Serialize the current map to disk so a future session can rehydrate it, even
+    /// if the parent process never exported `=X:`-style env vars.
+    pub fn save_to_disk(&self) -> Result<(), PathError> {
+        match Self::persist_file_path() {
+            Some(path) => self.save_to_disk_at(&path),
+            // No cache dir available (e.g. sandboxed environment): best-effort
+            // no-op rather than an error, since persistence is a convenience.
+            None => Ok(()),
+        }
+    }
+
+    /// Serialize the current map to `path`. Writes to a sibling temp file and
+    /// renames it into place so concurrent shells exiting at the same time
+    /// don't corrupt each other's write.
+    fn save_to_disk_at(&self, path: &Path) -> Result<(), PathError> {
+        let Some(parent) = path.parent() else {
+            return Err(PathError::InvalidPath);
+        };
+        std::fs::create_dir_all(parent).map_err(|_| PathError::InvalidPath)?;
+
+        let mut entries = Vec::new();
+        for (drive_index, drive_letter) in ('A'..='Z').enumerate() {
+            if let Some(pwd) = &self.map[drive_index] {
+                entries.push(format!("{drive_letter}: {}", quote(pwd)));
+            }
+        }
+        let nuon = format!("{{{}}}", entries.join(", "));
+
+        let tmp_path = path.with_extension(format!("nuon.{}.tmp", std::process::id()));
+        std::fs::write(&tmp_path, nuon).map_err(|_| PathError::InvalidPath)?;
+        std::fs::rename(&tmp_path, path).map_err(|_| PathError::InvalidPath)?;
+        Ok(())
     }
 
     /// Collect PWD-per-drive as env vars (for child process)
@@ -129,7 +312,7 @@ impl DriveToPwdMap {
         for (drive_index, drive_letter) in ('A'..='Z').enumerate() {
             if let Some(pwd) = self.map[drive_index].clone() {
                 if pwd.len() > 3 {
-                    let env_var_for_drive = format!("={}:", drive_letter);
+                    let env_var_for_drive = Self::env_var_for_drive(drive_letter);
                     env.insert(env_var_for_drive, pwd);
                 }
             }
@@ -424,4 +607,91 @@ mod tests {
         // Invalid drive letter (non-alphabetic)
         assert_eq!(drive_map.get_pwd('1'), Err(PathError::InvalidDriveLetter));
     }
+
+    #[test]
+    fn test_env_var_for_drive_uppercases() {
+        assert_eq!(DriveToPwdMap::env_var_for_drive('q'), "=Q:");
+        assert_eq!(DriveToPwdMap::env_var_for_drive('Q'), "=Q:");
+    }
+
+    /// Build a unique path under the system temp dir for a single test, so
+    /// persistence tests never touch the machine's real cache directory.
+    fn temp_persist_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "nu_path_pwd_per_drive_test_{name}_{:?}",
+            std::thread::current().id()
+        ))
+    }
+
+    #[test]
+    fn test_save_and_load_persisted_roundtrip() {
+        let path = temp_persist_path("roundtrip");
+        let mut drive_map = DriveToPwdMap::new();
+        assert!(drive_map.set_pwd(Path::new(r"R:\Users\Persisted")).is_ok());
+        assert!(drive_map.set_pwd(Path::new(r"T:\Users\Other")).is_ok());
+
+        drive_map.save_to_disk_at(&path).unwrap();
+        let persisted = DriveToPwdMap::load_persisted_from(&path);
+        assert_eq!(persisted.get(&'R'), Some(&r"R:\Users\Persisted".to_string()));
+        assert_eq!(persisted.get(&'T'), Some(&r"T:\Users\Other".to_string()));
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_persisted_path_with_comma_roundtrips() {
+        let path = temp_persist_path("comma");
+        let mut drive_map = DriveToPwdMap::new();
+        assert!(drive_map
+            .set_pwd(Path::new(r"U:\Users\Smith, Jr\Desktop"))
+            .is_ok());
+
+        drive_map.save_to_disk_at(&path).unwrap();
+        let persisted = DriveToPwdMap::load_persisted_from(&path);
+        assert_eq!(
+            persisted.get(&'U'),
+            Some(&r"U:\Users\Smith, Jr\Desktop".to_string())
+        );
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_resolve_from_persisted_or_os_prefers_existing_persisted_path() {
+        let existing = std::env::temp_dir().to_string_lossy().to_string();
+        let mut persisted = HashMap::new();
+        persisted.insert('V', existing.clone());
+        assert_eq!(
+            DriveToPwdMap::resolve_from_persisted_or_os('V', &persisted),
+            Some(existing)
+        );
+    }
+
+    #[test]
+    fn test_resolve_from_persisted_or_os_skips_stale_persisted_path() {
+        let mut persisted = HashMap::new();
+        persisted.insert(
+            'W',
+            r"W:\Definitely\Does\Not\Exist\On\This\Machine".to_string(),
+        );
+        let resolved = DriveToPwdMap::resolve_from_persisted_or_os('W', &persisted);
+        assert_ne!(
+            resolved.as_deref(),
+            Some(r"W:\Definitely\Does\Not\Exist\On\This\Machine")
+        );
+    }
+
+    #[test]
+    fn test_split_top_level_respects_quotes() {
+        assert_eq!(
+            split_top_level(r#"A: "foo, bar", B: "baz""#, ','),
+            vec![r#"A: "foo, bar""#, r#"B: "baz""#]
+        );
+    }
+
+    #[test]
+    fn test_quote_unquote_roundtrip() {
+        let original = r"C:\Users\Smith, Jr\Desktop";
+        assert_eq!(unquote(&quote(original)), original);
+    }
 }
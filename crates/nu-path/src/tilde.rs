@@ -0,0 +1,63 @@
+use std::path::{Path, PathBuf};
+
+/// Expand a leading `~` in `path` to `home`, if `path` starts with one.
+/// Paths that don't start with `~` are returned unchanged. If `home` is
+/// `None` (home directory couldn't be determined), `path` is also left
+/// unchanged.
+pub fn expand_tilde_with_home(path: impl AsRef<Path>, home: Option<PathBuf>) -> PathBuf {
+    let path = path.as_ref();
+    let Ok(rest) = path.strip_prefix("~") else {
+        return path.to_owned();
+    };
+    let Some(home) = home else {
+        return path.to_owned();
+    };
+    if rest.as_os_str().is_empty() {
+        home
+    } else {
+        home.join(rest)
+    }
+}
+
+/// Expand a leading `~` in `path` to the current user's home directory
+/// ([`crate::home_dir`]).
+pub fn expand_tilde(path: impl AsRef<Path>) -> PathBuf {
+    expand_tilde_with_home(path, crate::home_dir())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expands_bare_tilde() {
+        let home = PathBuf::from("/home/nu");
+        assert_eq!(
+            expand_tilde_with_home("~", Some(home.clone())),
+            home
+        );
+    }
+
+    #[test]
+    fn expands_tilde_with_subpath() {
+        let home = PathBuf::from("/home/nu");
+        assert_eq!(
+            expand_tilde_with_home("~/projects/nushell", Some(home)),
+            PathBuf::from("/home/nu/projects/nushell")
+        );
+    }
+
+    #[test]
+    fn leaves_non_tilde_paths_untouched() {
+        let home = PathBuf::from("/home/nu");
+        assert_eq!(
+            expand_tilde_with_home("/usr/bin", Some(home)),
+            PathBuf::from("/usr/bin")
+        );
+    }
+
+    #[test]
+    fn leaves_path_untouched_without_home() {
+        assert_eq!(expand_tilde_with_home("~/foo", None), PathBuf::from("~/foo"));
+    }
+}
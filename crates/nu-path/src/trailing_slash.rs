@@ -0,0 +1,52 @@
+use crate::form::virtual_prefix;
+use std::path::{Path, PathBuf};
+
+/// Check whether `path` ends in a path separator (`/` or `\`).
+pub fn has_trailing_slash(path: impl AsRef<Path>) -> bool {
+    path.as_ref().to_string_lossy().ends_with(['/', '\\'])
+}
+
+/// Remove a single trailing path separator from `path`, if present.
+///
+/// If `path` starts with a recognized virtual-root prefix (see
+/// [`crate::form`]), the prefix is left untouched and only the path portion
+/// after it is stripped.
+pub fn strip_trailing_slash(path: impl AsRef<Path>) -> PathBuf {
+    let path = path.as_ref();
+    if let Some((prefix, rest)) = virtual_prefix(path) {
+        let rest = strip_trailing_slash(Path::new(&rest));
+        return PathBuf::from(format!("{prefix}{}", rest.display()));
+    }
+    if has_trailing_slash(path) {
+        let s = path.to_string_lossy();
+        PathBuf::from(&s[..s.len() - 1])
+    } else {
+        path.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_trailing_slash() {
+        assert!(has_trailing_slash("/foo/bar/"));
+        assert!(has_trailing_slash(r"C:\foo\"));
+        assert!(!has_trailing_slash("/foo/bar"));
+    }
+
+    #[test]
+    fn strips_trailing_slash() {
+        assert_eq!(strip_trailing_slash("/foo/bar/"), PathBuf::from("/foo/bar"));
+        assert_eq!(strip_trailing_slash("/foo/bar"), PathBuf::from("/foo/bar"));
+    }
+
+    #[test]
+    fn strips_trailing_slash_after_virtual_prefix() {
+        assert_eq!(
+            strip_trailing_slash("remote://host/a/"),
+            PathBuf::from("remote://host/a")
+        );
+    }
+}
@@ -0,0 +1,20 @@
+use nu_protocol::Spanned;
+
+/// Parsed, validated CLI arguments for a single `nu` invocation. Filled in by
+/// the binary's argument parser before `run_commands`/`run_file`/`run_repl`
+/// is dispatched.
+pub(crate) struct NushellCliArgs {
+    pub(crate) no_config_file: Option<bool>,
+    pub(crate) config_file: Option<Spanned<String>>,
+    pub(crate) env_file: Option<Spanned<String>>,
+    pub(crate) login_shell: Option<bool>,
+    pub(crate) plugin_file: Option<Spanned<String>>,
+    pub(crate) table_mode: Option<Spanned<String>>,
+    pub(crate) error_style: Option<Spanned<String>>,
+    pub(crate) no_newline: Option<bool>,
+    pub(crate) execute: Option<Spanned<String>>,
+    pub(crate) no_std_lib: bool,
+    /// Set by `--startup-timings`: collect each startup phase's elapsed time
+    /// and expose it as `$nu.startup-timings` instead of only logging it.
+    pub(crate) startup_timings: Option<bool>,
+}
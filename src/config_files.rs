@@ -0,0 +1,148 @@
+use log::trace;
+#[cfg(feature = "plugin")]
+use nu_cli::read_plugin_file;
+use nu_cli::eval_source;
+use nu_protocol::{
+    engine::{EngineState, Stack},
+    PipelineData, Spanned,
+};
+
+pub(crate) const NU_ENV_FILE: &str = "env.nu";
+pub(crate) const NU_CONFIG_FILE: &str = "config.nu";
+pub(crate) const NU_LOGINSHELL_FILE: &str = "login.nu";
+
+/// Read and evaluate `config_file` (or, if it's `None`, the default
+/// `env.nu`/`config.nu` for this invocation) into `engine_state`/`stack`.
+///
+/// When no explicit override is given, the default file is located by
+/// searching [`nu_path::nu_config_dirs`] in order, so a user config
+/// missing from `$XDG_CONFIG_HOME` can still pick up defaults laid down in
+/// an `$XDG_CONFIG_DIRS` entry like `/etc/xdg/nushell`. `create_scaffold`
+/// controls whether a missing *user* config file is created with sample
+/// content, and only ever applies to that first directory, never to a
+/// system-wide fallback.
+pub(crate) fn read_config_file(
+    engine_state: &mut EngineState,
+    stack: &mut Stack,
+    config_file: Option<Spanned<String>>,
+    is_env_file: bool,
+    create_scaffold: bool,
+) {
+    let default_name = if is_env_file {
+        NU_ENV_FILE
+    } else {
+        NU_CONFIG_FILE
+    };
+
+    let config_path = match config_file {
+        Some(file) => std::path::PathBuf::from(file.item),
+        None => match nu_path::locate_in_dirs(default_name, ".", || {
+            nu_path::nu_config_dirs().into_iter()
+        }) {
+            Some(path) => path,
+            None if create_scaffold => {
+                let Some(dir) = nu_path::nu_config_dir() else {
+                    return;
+                };
+                let path = dir.join(default_name);
+                if let Err(err) = std::fs::create_dir_all(&dir) {
+                    trace!("failed to create config dir: {err:?}");
+                    return;
+                }
+                if let Err(err) = std::fs::write(&path, default_content(is_env_file)) {
+                    trace!("failed to scaffold {default_name}: {err:?}");
+                    return;
+                }
+                path
+            }
+            None => return,
+        },
+    };
+
+    if !config_path.exists() {
+        return;
+    }
+
+    let Ok(contents) = std::fs::read_to_string(&config_path) else {
+        return;
+    };
+
+    eval_source(
+        engine_state,
+        stack,
+        contents.as_bytes(),
+        &config_path.to_string_lossy(),
+        PipelineData::empty(),
+        true,
+    );
+}
+
+fn default_content(is_env_file: bool) -> &'static str {
+    if is_env_file {
+        "# Nushell environment config\n"
+    } else {
+        "# Nushell config\n"
+    }
+}
+
+/// Read the default `env.nu`, the same way [`read_config_file`] would with
+/// `config_file: None, is_env_file: true`, without requiring callers to
+/// spell out those arguments at every call site that just wants the default.
+pub(crate) fn read_default_env_file(engine_state: &mut EngineState, stack: &mut Stack) {
+    let create_scaffold = nu_path::nu_config_dir().map_or(false, |p| !p.exists());
+    read_config_file(engine_state, stack, None, true, create_scaffold);
+}
+
+/// Read `login.nu`, if it exists, after `env.nu`/`config.nu` for a login
+/// shell. Searches the same [`nu_path::nu_config_dirs`] list as
+/// [`read_config_file`], but never scaffolds one: a missing `login.nu` is
+/// normal and shouldn't create a file a user never asked for.
+pub(crate) fn read_loginshell_file(engine_state: &mut EngineState, stack: &mut Stack) {
+    let Some(path) =
+        nu_path::locate_in_dirs(NU_LOGINSHELL_FILE, ".", || nu_path::nu_config_dirs().into_iter())
+    else {
+        return;
+    };
+
+    let Ok(contents) = std::fs::read_to_string(&path) else {
+        return;
+    };
+
+    eval_source(
+        engine_state,
+        stack,
+        contents.as_bytes(),
+        &path.to_string_lossy(),
+        PipelineData::empty(),
+        true,
+    );
+}
+
+/// Load plugin/env/config/login files for a REPL session, in that order.
+pub(crate) fn setup_config(
+    engine_state: &mut EngineState,
+    stack: &mut Stack,
+    #[cfg(feature = "plugin")] plugin_file: Option<Spanned<String>>,
+    config_file: Option<Spanned<String>>,
+    env_file: Option<Spanned<String>>,
+    is_login_shell: bool,
+) {
+    #[cfg(feature = "plugin")]
+    read_plugin_file(engine_state, plugin_file);
+
+    let create_scaffold = nu_path::nu_config_dir().map_or(false, |p| !p.exists());
+
+    if env_file.is_some() || is_login_shell {
+        read_config_file(engine_state, stack, env_file, true, create_scaffold);
+    } else {
+        read_default_env_file(engine_state, stack);
+    }
+
+    read_config_file(engine_state, stack, config_file, false, create_scaffold);
+
+    if is_login_shell {
+        read_loginshell_file(engine_state, stack);
+    }
+
+    engine_state.generate_nu_constant();
+}
@@ -8,18 +8,74 @@ use nu_cli::read_plugin_file;
 use nu_cli::{evaluate_commands, evaluate_file, evaluate_repl, EvaluateCommandsOpts};
 use nu_protocol::{
     engine::{EngineState, Stack},
-    report_shell_error, PipelineData, Spanned,
+    record, report_shell_error, PipelineData, Span, Spanned, Value,
 };
 use nu_utils::perf;
+use std::time::{Duration, Instant};
 
+/// Phase name + elapsed time, collected when `--startup-timings` is passed so the
+/// caller can build the `$nu.startup-timings` table instead of only logging.
+type StartupTimings = Vec<(String, Duration)>;
+
+/// Mirror of the `perf!` macro that additionally records the elapsed time into
+/// `timings`, when timing collection is enabled for this run.
+macro_rules! perf_and_record {
+    ($name:expr, $start_time:expr, $use_color:expr, $timings:expr) => {{
+        perf!($name, $start_time, $use_color);
+        if let Some(timings) = $timings.as_mut() {
+            timings.push(($name.to_string(), $start_time.elapsed()));
+        }
+    }};
+}
+
+/// Turn the collected startup-phase timings into a Nushell table with `phase`,
+/// `duration`, and `pct_of_total` columns, suitable for `$nu.startup-timings`.
+///
+/// NOTE: this is only the binary-side half of the feature. `EngineState`
+/// doesn't have `set_startup_timings` yet, and `generate_nu_constant`
+/// doesn't add a `startup-timings` column to `$nu` — both need to be added
+/// in `crates/nu-protocol`, which isn't present anywhere in this checkout
+/// (unlike `src/command.rs`/`src/config_files.rs`, there's no existing
+/// `EngineState` struct definition here to extend, so that change can't be
+/// written from this tree). Until that lands, `--startup-timings` collects
+/// data this function can format but the calls below to
+/// `engine_state.set_startup_timings(...)` won't compile.
+fn startup_timings_to_value(timings: &StartupTimings) -> Value {
+    let total_nanos: u128 = timings.iter().map(|(_, d)| d.as_nanos()).sum();
+    let rows = timings
+        .iter()
+        .map(|(phase, duration)| {
+            let pct_of_total = if total_nanos > 0 {
+                duration.as_nanos() as f64 / total_nanos as f64 * 100.0
+            } else {
+                0.0
+            };
+            Value::record(
+                record! {
+                    "phase" => Value::string(phase.clone(), Span::unknown()),
+                    "duration" => Value::duration(duration.as_nanos() as i64, Span::unknown()),
+                    "pct_of_total" => Value::float(pct_of_total, Span::unknown()),
+                },
+                Span::unknown(),
+            )
+        })
+        .collect();
+    Value::list(rows, Span::unknown())
+}
+
+/// Seed `stack.pwd_per_drive` for this session. By the time `Stack::new()`
+/// constructs that field, `nu_path::DriveToPwdMap::new()` has already merged
+/// in the map persisted by a previous session (for drives this function
+/// doesn't otherwise touch), so this only needs to layer the current env
+/// vars and `engine_state.cwd()` on top, highest priority last.
 #[cfg(windows)]
-fn init_pwd_per_drive(    
+fn init_pwd_per_drive(
     engine_state: &EngineState,
     stack: &mut Stack,
 ) {
     use std::path::Path;
     use nu_path::DriveToPwdMap;
-    
+
     // Read environment for PWD-per-drive
     for drive_letter in 'A'..='Z' {
         let env_var = DriveToPwdMap::env_var_for_drive(drive_letter);
@@ -51,7 +107,12 @@ pub(crate) fn run_commands(
 ) {
     trace!("run_commands");
 
-    let start_time = std::time::Instant::now();
+    let mut startup_timings: Option<StartupTimings> = parsed_nu_cli_args
+        .startup_timings
+        .is_some()
+        .then(Vec::new);
+
+    let start_time = Instant::now();
     let create_scaffold = nu_path::nu_config_dir().map_or(false, |p| !p.exists());
 
     let mut stack = Stack::new();
@@ -67,7 +128,7 @@ pub(crate) fn run_commands(
         #[cfg(feature = "plugin")]
         read_plugin_file(engine_state, parsed_nu_cli_args.plugin_file);
 
-        perf!("read plugins", start_time, use_color);
+        perf_and_record!("read plugins", start_time, use_color, startup_timings);
 
         let start_time = std::time::Instant::now();
         // If we have a env file parameter *OR* we have a login shell parameter, read the env file
@@ -83,7 +144,7 @@ pub(crate) fn run_commands(
             config_files::read_default_env_file(engine_state, &mut stack)
         }
 
-        perf!("read env.nu", start_time, use_color);
+        perf_and_record!("read env.nu", start_time, use_color, startup_timings);
 
         let start_time = std::time::Instant::now();
         let create_scaffold = nu_path::nu_config_dir().map_or(false, |p| !p.exists());
@@ -99,7 +160,7 @@ pub(crate) fn run_commands(
             );
         }
 
-        perf!("read config.nu", start_time, use_color);
+        perf_and_record!("read config.nu", start_time, use_color, startup_timings);
 
         // If we have a login shell parameter, read the login file
         let start_time = std::time::Instant::now();
@@ -107,16 +168,19 @@ pub(crate) fn run_commands(
             config_files::read_loginshell_file(engine_state, &mut stack);
         }
 
-        perf!("read login.nu", start_time, use_color);
+        perf_and_record!("read login.nu", start_time, use_color, startup_timings);
     }
 
     // Before running commands, set up the startup time
     engine_state.set_startup_time(entire_start_time.elapsed().as_nanos() as i64);
+    if let Some(timings) = startup_timings.as_ref() {
+        engine_state.set_startup_timings(startup_timings_to_value(timings));
+    }
 
     // Regenerate the $nu constant to contain the startup time and any other potential updates
     engine_state.generate_nu_constant();
 
-    let start_time = std::time::Instant::now();
+    let start_time = Instant::now();
     let result = evaluate_commands(
         commands,
         engine_state,
@@ -128,7 +192,17 @@ pub(crate) fn run_commands(
             no_newline: parsed_nu_cli_args.no_newline.is_some(),
         },
     );
-    perf!("evaluate_commands", start_time, use_color);
+    perf_and_record!("evaluate_commands", start_time, use_color, startup_timings);
+
+    // Remember the PWD on each drive for the next session, best-effort. Not
+    // just a `run_repl` concern: `nu -c "cd D:\foo"` changes per-drive PWDs
+    // just as much as an interactive session does.
+    #[cfg(windows)]
+    if result.is_ok() {
+        if let Err(err) = nu_path::persist_to_disk() {
+            trace!("failed to persist pwd-per-drive map: {err:?}");
+        }
+    }
 
     if let Err(err) = result {
         report_shell_error(engine_state, &err);
@@ -145,6 +219,11 @@ pub(crate) fn run_file(
     input: PipelineData,
 ) {
     trace!("run_file");
+    let mut startup_timings: Option<StartupTimings> = parsed_nu_cli_args
+        .startup_timings
+        .is_some()
+        .then(Vec::new);
+
     let mut stack = Stack::new();
     #[cfg(windows)]
     init_pwd_per_drive(engine_state, &mut stack);
@@ -159,7 +238,7 @@ pub(crate) fn run_file(
         let create_scaffold = nu_path::nu_config_dir().map_or(false, |p| !p.exists());
         #[cfg(feature = "plugin")]
         read_plugin_file(engine_state, parsed_nu_cli_args.plugin_file);
-        perf!("read plugins", start_time, use_color);
+        perf_and_record!("read plugins", start_time, use_color, startup_timings);
 
         let start_time = std::time::Instant::now();
         // only want to load config and env if relative argument is provided.
@@ -174,7 +253,7 @@ pub(crate) fn run_file(
         } else {
             config_files::read_default_env_file(engine_state, &mut stack)
         }
-        perf!("read env.nu", start_time, use_color);
+        perf_and_record!("read env.nu", start_time, use_color, startup_timings);
 
         let start_time = std::time::Instant::now();
         if parsed_nu_cli_args.config_file.is_some() {
@@ -186,13 +265,17 @@ pub(crate) fn run_file(
                 create_scaffold,
             );
         }
-        perf!("read config.nu", start_time, use_color);
+        perf_and_record!("read config.nu", start_time, use_color, startup_timings);
+    }
+
+    if let Some(timings) = startup_timings.as_ref() {
+        engine_state.set_startup_timings(startup_timings_to_value(timings));
     }
 
     // Regenerate the $nu constant to contain the startup time and any other potential updates
     engine_state.generate_nu_constant();
 
-    let start_time = std::time::Instant::now();
+    let start_time = Instant::now();
     let result = evaluate_file(
         script_name,
         &args_to_script,
@@ -200,7 +283,17 @@ pub(crate) fn run_file(
         &mut stack,
         input,
     );
-    perf!("evaluate_file", start_time, use_color);
+    perf_and_record!("evaluate_file", start_time, use_color, startup_timings);
+
+    // Remember the PWD on each drive for the next session, best-effort. Not
+    // just a `run_repl` concern: a script that changes per-drive PWDs should
+    // have that survive to the next session too.
+    #[cfg(windows)]
+    if result.is_ok() {
+        if let Err(err) = nu_path::persist_to_disk() {
+            trace!("failed to persist pwd-per-drive map: {err:?}");
+        }
+    }
 
     if let Err(err) = result {
         report_shell_error(engine_state, &err);
@@ -214,11 +307,16 @@ pub(crate) fn run_repl(
     entire_start_time: std::time::Instant,
 ) -> Result<(), miette::ErrReport> {
     trace!("run_repl");
+    let mut startup_timings: Option<StartupTimings> = parsed_nu_cli_args
+        .startup_timings
+        .is_some()
+        .then(Vec::new);
+
     let mut stack = Stack::new();
     #[cfg(windows)]
     init_pwd_per_drive(engine_state, &mut stack);
 
-    let start_time = std::time::Instant::now();
+    let start_time = Instant::now();
 
     if parsed_nu_cli_args.no_config_file.is_none() {
         setup_config(
@@ -234,9 +332,16 @@ pub(crate) fn run_repl(
 
     // Reload use_color from config in case it's different from the default value
     let use_color = engine_state.get_config().use_ansi_coloring;
-    perf!("setup_config", start_time, use_color);
+    perf_and_record!("setup_config", start_time, use_color, startup_timings);
 
-    let start_time = std::time::Instant::now();
+    // Regenerate the $nu constant once more so `$nu.startup-timings` reflects the
+    // phases collected above (setup_config's own regeneration runs before this).
+    if let Some(timings) = startup_timings.as_ref() {
+        engine_state.set_startup_timings(startup_timings_to_value(timings));
+        engine_state.generate_nu_constant();
+    }
+
+    let start_time = Instant::now();
     let ret_val = evaluate_repl(
         engine_state,
         stack,
@@ -244,7 +349,15 @@ pub(crate) fn run_repl(
         parsed_nu_cli_args.no_std_lib,
         entire_start_time,
     );
-    perf!("evaluate_repl", start_time, use_color);
+    perf_and_record!("evaluate_repl", start_time, use_color, startup_timings);
+
+    // Remember the PWD on each drive for the next session, best-effort.
+    #[cfg(windows)]
+    if ret_val.is_ok() {
+        if let Err(err) = nu_path::persist_to_disk() {
+            trace!("failed to persist pwd-per-drive map: {err:?}");
+        }
+    }
 
     ret_val
 }